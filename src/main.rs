@@ -1,6 +1,9 @@
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
+use nix::mount::{mount, umount2, MntFlags, MsFlags};
 use nix::sched::{setns, CloneFlags};
+use rustix::fd::OwnedFd;
 use rustix::mount::{move_mount, open_tree, MoveMountFlags, OpenTreeFlags};
+use serde::Deserialize;
 use std::os::fd::AsFd;
 // use rustix::process::{setns, Namespace};
 use std::fs::File;
@@ -10,22 +13,474 @@ use std::process;
 
 #[derive(Parser)]
 #[command(author, version, about)]
-struct Args {
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Move an existing mount tree, or mount a filesystem with --fstype, into
+    /// a target namespace
+    Mount(MountArgs),
+    /// Recursively tear down a mount tree
+    Umount(UmountArgs),
+    /// Apply a declarative batch of mounts from a TOML config file
+    Apply(ApplyArgs),
+    /// Assemble an overlay filesystem from lower/upper/work layers
+    Overlay(OverlayArgs),
+}
+
+#[derive(clap::Args)]
+struct MountArgs {
     /// Target mountpoint directory
     #[arg(long)]
     target: String,
     /// Source device or path
     #[arg(long)]
     source: String,
+    #[command(flatten)]
+    ns: NamespaceTarget,
+    /// Filesystem type to mount (e.g. ext4, xfs, tmpfs); when given, `source`
+    /// is mounted directly instead of moving an existing mount tree
+    #[arg(long)]
+    fstype: Option<String>,
+    /// Mount data option in `key` or `key=value` form; may be repeated
+    #[arg(long = "option")]
+    options: Vec<String>,
+    /// Comma-separated mount flags (ro,nosuid,nodev,noexec,relatime)
+    #[arg(long, value_delimiter = ',')]
+    flags: Vec<String>,
+    /// Set mount propagation on the target after mounting; defaults to
+    /// `private` when given without a value
+    #[arg(long, value_enum, num_args = 0..=1, default_missing_value = "private")]
+    propagation: Option<Propagation>,
+    /// Apply --propagation recursively to the whole cloned subtree
+    #[arg(long)]
+    recursive_propagation: bool,
+}
+
+#[derive(Clone, ValueEnum)]
+enum Propagation {
+    Private,
+    Slave,
+    Shared,
+    Unbindable,
+}
+
+impl Propagation {
+    /// Parse a propagation mode from a config file string (clap handles the
+    /// CLI `--propagation` flag itself via `ValueEnum`).
+    fn parse(s: &str) -> Result<Self, String> {
+        match s.to_lowercase().as_str() {
+            "private" => Ok(Propagation::Private),
+            "slave" => Ok(Propagation::Slave),
+            "shared" => Ok(Propagation::Shared),
+            "unbindable" => Ok(Propagation::Unbindable),
+            other => Err(format!("unknown propagation mode: {}", other)),
+        }
+    }
+}
+
+#[derive(clap::Args)]
+struct UmountArgs {
+    /// Target mountpoint directory to tear down
+    #[arg(long)]
+    target: String,
+    #[command(flatten)]
+    ns: NamespaceTarget,
+}
+
+#[derive(clap::Args)]
+struct ApplyArgs {
+    /// Path to a TOML file describing a list of mount entries to apply in order
+    #[arg(long)]
+    config: String,
+    /// Unmount the entries already applied in this run, in reverse order, if a
+    /// later entry fails
+    #[arg(long)]
+    rollback: bool,
+}
+
+#[derive(clap::Args)]
+struct OverlayArgs {
+    /// Target mountpoint directory
+    #[arg(long)]
+    target: String,
+    #[command(flatten)]
+    ns: NamespaceTarget,
+    /// Lower (read-only) layer directory in priority order; repeatable.
+    /// Joined with `:` in the given order, so the rightmost --lower is the
+    /// lowest layer, as overlayfs expects
+    #[arg(long = "lower")]
+    lowers: Vec<String>,
+    /// Upper (writable) layer directory; requires --work
+    #[arg(long)]
+    upper: Option<String>,
+    /// Work directory used by the upper layer; requires --upper
+    #[arg(long)]
+    work: Option<String>,
+}
+
+/// Selects the mount namespace to operate in; exactly one of the two must
+/// be given.
+#[derive(clap::Args)]
+#[group(required = true, multiple = false)]
+struct NamespaceTarget {
     /// Path to target mount namespace
     #[arg(long)]
-    mount_namespace: String,
+    mount_namespace: Option<String>,
+    /// PID whose mount namespace to target (resolves to /proc/<pid>/ns/mnt)
+    #[arg(long)]
+    pid: Option<u32>,
+}
+
+impl NamespaceTarget {
+    /// Resolve the configured target to a `/proc/.../ns/mnt`-style path.
+    fn resolve(&self) -> String {
+        match (&self.mount_namespace, self.pid) {
+            (Some(path), None) => path.clone(),
+            (None, Some(pid)) => format!("/proc/{}/ns/mnt", pid),
+            _ => unreachable!("clap guarantees exactly one of mount_namespace/pid is set"),
+        }
+    }
+}
+
+/// A single mount to perform, independent of where its values came from
+/// (the `mount` subcommand's flags, or one entry of an `apply` config file).
+struct MountSpec {
+    target: String,
+    source: String,
+    fstype: Option<String>,
+    options: Vec<String>,
+    flags: Vec<String>,
+    propagation: Option<Propagation>,
+    recursive_propagation: bool,
+}
+
+/// A batch of mounts to apply in order, deserialized from `--config`.
+#[derive(Deserialize)]
+struct ConfigFile {
+    /// Default mount namespace path shared by entries that don't override it
+    mount_namespace: Option<String>,
+    /// Default target PID shared by entries that don't override it
+    pid: Option<u32>,
+    #[serde(default)]
+    mounts: Vec<ConfigMount>,
+}
+
+#[derive(Deserialize)]
+struct ConfigMount {
+    source: String,
+    target: String,
+    fstype: Option<String>,
+    #[serde(default)]
+    options: Vec<String>,
+    #[serde(default)]
+    flags: Vec<String>,
+    propagation: Option<String>,
+    #[serde(default)]
+    recursive_propagation: bool,
+    mount_namespace: Option<String>,
+    pid: Option<u32>,
+}
+
+impl ConfigMount {
+    fn to_spec(&self) -> Result<MountSpec, String> {
+        let propagation = self
+            .propagation
+            .as_deref()
+            .map(Propagation::parse)
+            .transpose()?;
+        Ok(MountSpec {
+            target: self.target.clone(),
+            source: self.source.clone(),
+            fstype: self.fstype.clone(),
+            options: self.options.clone(),
+            flags: self.flags.clone(),
+            propagation,
+            recursive_propagation: self.recursive_propagation,
+        })
+    }
+
+    /// Resolve this entry's mount namespace, falling back to the config
+    /// file's shared default when the entry doesn't specify its own.
+    fn resolve_ns(
+        &self,
+        default_ns: &Option<String>,
+        default_pid: Option<u32>,
+    ) -> Result<String, String> {
+        if self.mount_namespace.is_some() && self.pid.is_some() {
+            return Err("entry specifies both mount_namespace and pid".to_string());
+        }
+        if let Some(path) = &self.mount_namespace {
+            return Ok(path.clone());
+        }
+        if let Some(pid) = self.pid {
+            return Ok(format!("/proc/{}/ns/mnt", pid));
+        }
+        if let Some(path) = default_ns {
+            return Ok(path.clone());
+        }
+        if let Some(pid) = default_pid {
+            return Ok(format!("/proc/{}/ns/mnt", pid));
+        }
+        Err("no mount_namespace or pid specified (entry or file default)".to_string())
+    }
 }
 
 fn main() {
-    let args = Args::parse();
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Mount(args) => run_mount(args),
+        Command::Umount(args) => run_umount(args),
+        Command::Apply(args) => run_apply(args),
+        Command::Overlay(args) => run_overlay(args),
+    }
+}
+
+/// Validate a directory that must already exist on the host before we
+/// setns away from it, so failures are reported against host paths.
+fn validate_host_dir(path: &str, desc: &str) -> Result<(), String> {
+    let p = Path::new(path);
+    if !p.exists() || !p.is_dir() {
+        return Err(format!(
+            "{} does not exist or is not a directory: {}",
+            desc, path
+        ));
+    }
+    Ok(())
+}
+
+fn open_ns_file(path: &str, desc: &str) -> File {
+    match File::open(path) {
+        Ok(f) => f,
+        Err(e) => {
+            eprintln!("open {} failed: {}", desc, e);
+            process::exit(1);
+        }
+    }
+}
+
+fn enter_namespace(ns_file: &File, desc: &str) {
+    if let Err(e) = setns(ns_file, CloneFlags::CLONE_NEWNS) {
+        eprintln!("setns to {} failed: {}", desc, e);
+        process::exit(1);
+    }
+}
+
+fn parse_ms_flags(flags: &[String]) -> Result<MsFlags, String> {
+    let mut result = MsFlags::empty();
+    for flag in flags {
+        result |= match flag.as_str() {
+            "ro" => MsFlags::MS_RDONLY,
+            "nosuid" => MsFlags::MS_NOSUID,
+            "nodev" => MsFlags::MS_NODEV,
+            "noexec" => MsFlags::MS_NOEXEC,
+            "relatime" => MsFlags::MS_RELATIME,
+            other => return Err(format!("unknown mount flag: {}", other)),
+        };
+    }
+    Ok(result)
+}
+
+/// When `spec` has no `fstype`, clone the existing mount tree at `spec.source`
+/// so it can be moved into place; this must run in whichever mount namespace
+/// `spec.source` is actually visible in, so callers control the timing
+/// relative to `setns` rather than `apply_mount` doing it implicitly.
+fn open_move_source(spec: &MountSpec) -> Result<Option<OwnedFd>, String> {
+    if spec.fstype.is_some() {
+        return Ok(None);
+    }
+    let source = Path::new(&spec.source);
+    if !source.exists() || !source.is_dir() {
+        return Err(format!(
+            "source does not exist or is not a directory: {}",
+            spec.source
+        ));
+    }
+    let fd = open_tree(
+        rustix::fs::CWD,
+        source,
+        OpenTreeFlags::OPEN_TREE_CLONE | OpenTreeFlags::AT_RECURSIVE,
+    )
+    .map_err(|e| format!("open source {} failed: {}", spec.source, e))?;
+    Ok(Some(fd))
+}
+
+/// Perform one mount: create/prepare the target, move or mount the source
+/// into place, then apply propagation. Assumes the caller has already
+/// entered the correct mount namespace and, for the move-mode case, opened
+/// `source_fd` via `open_move_source`.
+fn apply_mount(spec: &MountSpec, source_fd: Option<OwnedFd>) -> Result<(), String> {
+    if spec.recursive_propagation && spec.propagation.is_none() {
+        return Err("--recursive-propagation has no effect without --propagation".to_string());
+    }
+
+    let target = Path::new(&spec.target);
+    if !target.exists() || !target.is_dir() {
+        return Err(format!(
+            "target does not exist or is not a directory: {}",
+            spec.target
+        ));
+    }
+
+    // Create the target directory with permission 755 before mounting
+    std::fs::create_dir_all(target)
+        .map_err(|e| format!("failed to create target directory {}: {}", spec.target, e))?;
+    std::fs::set_permissions(target, std::fs::Permissions::from_mode(0o755)).map_err(|e| {
+        format!(
+            "failed to set permissions on target directory {}: {}",
+            spec.target, e
+        )
+    })?;
+
+    match source_fd {
+        Some(source_fd) => {
+            if !spec.options.is_empty() || !spec.flags.is_empty() {
+                return Err(
+                    "--option/--flags have no effect without --fstype; pass --fstype or drop them"
+                        .to_string(),
+                );
+            }
+            move_mount(
+                source_fd.as_fd(),
+                "",
+                rustix::fs::CWD,
+                target,
+                MoveMountFlags::MOVE_MOUNT_F_EMPTY_PATH,
+            )
+            .map_err(|e| format!("move_mount failed: {}", e))?;
+        }
+        None => {
+            let fstype = spec
+                .fstype
+                .as_deref()
+                .expect("fstype set when source_fd is None");
+            let flags = parse_ms_flags(&spec.flags)?;
+            let data = if spec.options.is_empty() {
+                None
+            } else {
+                Some(spec.options.join(","))
+            };
+            mount(
+                Some(spec.source.as_str()),
+                target,
+                Some(fstype),
+                flags,
+                data.as_deref(),
+            )
+            .map_err(|e| {
+                format!(
+                    "mount {} ({}) on {} failed: {}",
+                    spec.source, fstype, spec.target, e
+                )
+            })?;
+        }
+    }
+
+    if let Some(propagation) = &spec.propagation {
+        let mut pflags = match propagation {
+            Propagation::Private => MsFlags::MS_PRIVATE,
+            Propagation::Slave => MsFlags::MS_SLAVE,
+            Propagation::Shared => MsFlags::MS_SHARED,
+            Propagation::Unbindable => MsFlags::MS_UNBINDABLE,
+        };
+        if spec.recursive_propagation {
+            pflags |= MsFlags::MS_REC;
+        }
+        mount(None::<&str>, target, None::<&str>, pflags, None::<&str>)
+            .map_err(|e| format!("set propagation on {} failed: {}", spec.target, e))?;
+    }
+
+    Ok(())
+}
+
+fn run_mount(args: MountArgs) {
+    let spec = MountSpec {
+        target: args.target,
+        source: args.source,
+        fstype: args.fstype,
+        options: args.options,
+        flags: args.flags,
+        propagation: args.propagation,
+        recursive_propagation: args.recursive_propagation,
+    };
+
+    // The clone of an existing mount tree must happen in the host
+    // namespace, before any setns, since that's where `spec.source` lives.
+    let source_fd = match open_move_source(&spec) {
+        Ok(fd) => fd,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let orig_ns = open_ns_file("/proc/self/ns/mnt", "original mount namespace");
+    let target_ns = args.ns.resolve();
+    let ns_file = open_ns_file(&target_ns, &target_ns);
+    enter_namespace(&ns_file, &target_ns);
+
+    if let Err(e) = apply_mount(&spec, source_fd) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+
+    // restore original namespace
+    enter_namespace(&orig_ns, "original mount namespace");
+}
+
+/// Collect every mountpoint at or below `target`, deepest first, by reading
+/// the current mount namespace's `/proc/self/mountinfo`.
+fn mountpoints_under(target: &str) -> Vec<String> {
+    let content = match std::fs::read_to_string("/proc/self/mountinfo") {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("failed to read /proc/self/mountinfo: {}", e);
+            process::exit(1);
+        }
+    };
+    let target = target.trim_end_matches('/');
+    let prefix = format!("{}/", target);
+    let mut points: Vec<String> = content
+        .lines()
+        .filter_map(|line| line.split_whitespace().nth(4))
+        .filter(|mp| *mp == target || mp.starts_with(&prefix))
+        .map(|s| s.to_string())
+        .collect();
+    points.sort_by_key(|p| std::cmp::Reverse(p.len()));
+    points
+}
+
+/// Recursively tear down everything mounted at or below `target` in the
+/// current mount namespace, deepest first, falling back to a lazy unmount
+/// on `EBUSY`. `target` is canonicalized first since `/proc/self/mountinfo`
+/// always reports the kernel's canonical, absolute mount path.
+fn teardown_target(target: &str) -> Result<(), String> {
+    let canonical = std::fs::canonicalize(target)
+        .map_err(|e| format!("failed to canonicalize target {}: {}", target, e))?;
+    let canonical = canonical.to_string_lossy().into_owned();
+    for mountpoint in mountpoints_under(&canonical) {
+        if let Err(e) = umount2(mountpoint.as_str(), MntFlags::empty()) {
+            if e == nix::errno::Errno::EBUSY {
+                umount2(mountpoint.as_str(), MntFlags::MNT_DETACH)
+                    .map_err(|e| format!("lazy umount of {} failed: {}", mountpoint, e))?;
+            } else {
+                return Err(format!("umount of {} failed: {}", mountpoint, e));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn run_umount(args: UmountArgs) {
+    let orig_ns = open_ns_file("/proc/self/ns/mnt", "original mount namespace");
+    let target_ns = args.ns.resolve();
+    let ns_file = open_ns_file(&target_ns, &target_ns);
+    enter_namespace(&ns_file, &target_ns);
 
-    // Ensure target exists and is a directory
     let target = Path::new(&args.target);
     if !target.exists() || !target.is_dir() {
         eprintln!(
@@ -34,80 +489,166 @@ fn main() {
         );
         process::exit(1);
     }
-    // Ensure source exists and is a directory
-    let source = Path::new(&args.source);
-    if !source.exists() || !source.is_dir() {
-        eprintln!(
-            "source does not exist or is not a directory: {}",
-            args.source
-        );
+
+    if let Err(e) = teardown_target(&args.target) {
+        eprintln!("{}", e);
         process::exit(1);
     }
-    let source_fd = match open_tree(
-        rustix::fs::CWD,
-        source,
-        OpenTreeFlags::OPEN_TREE_CLONE | OpenTreeFlags::AT_RECURSIVE,
-    ) {
-        Ok(fd) => fd,
+
+    // restore original namespace
+    enter_namespace(&orig_ns, "original mount namespace");
+}
+
+/// Report that entry `index` failed, roll back previously-applied entries in
+/// reverse order if requested, and exit non-zero.
+fn fail_entry(index: usize, err: &str, rollback: bool, applied: &[(String, String)]) -> ! {
+    eprintln!("entry {} failed: {}", index, err);
+    if rollback {
+        for (applied_target, applied_ns) in applied.iter().rev() {
+            let ns_file = open_ns_file(applied_ns, applied_ns);
+            enter_namespace(&ns_file, applied_ns);
+            // Only the topmost mount at the target is the one this entry made;
+            // mounts it stacked over are left in place.
+            let result = match umount2(applied_target.as_str(), MntFlags::empty()) {
+                Err(nix::errno::Errno::EBUSY) => {
+                    umount2(applied_target.as_str(), MntFlags::MNT_DETACH)
+                }
+                other => other,
+            };
+            if let Err(e) = result {
+                eprintln!("rollback of {} failed: {}", applied_target, e);
+            }
+        }
+    }
+    process::exit(1);
+}
+
+fn run_apply(args: ApplyArgs) {
+    let content = match std::fs::read_to_string(&args.config) {
+        Ok(c) => c,
         Err(e) => {
-            eprintln!("open source {} failed: {}", args.source, e);
+            eprintln!("failed to read config {}: {}", args.config, e);
             process::exit(1);
         }
     };
-    let orig_ns = match File::open("/proc/self/ns/mnt") {
-        Ok(f) => f,
+    let config: ConfigFile = match toml::from_str(&content) {
+        Ok(c) => c,
         Err(e) => {
-            eprintln!("open original mount namespace failed: {}", e);
+            eprintln!("failed to parse config {}: {}", args.config, e);
             process::exit(1);
         }
     };
-    // Optionally setns into mount namespace
-    // Mount namespace switching using nix::setns
-    if !args.mount_namespace.is_empty() {
-        let ns_file = match File::open(&args.mount_namespace) {
-            Ok(f) => f,
+    if config.mount_namespace.is_some() && config.pid.is_some() {
+        eprintln!("config: only one of mount_namespace or pid may be set at the top level");
+        process::exit(1);
+    }
+
+    let orig_ns = open_ns_file("/proc/self/ns/mnt", "original mount namespace");
+    // Track the namespace we're currently in so that consecutive entries in
+    // the same namespace only setns once, and we restore exactly once at the end.
+    let mut current_ns: Option<String> = None;
+    // (target, namespace) pairs, recorded in the namespace each mount was
+    // actually made in, so rollback can setns back to tear each one down.
+    let mut applied: Vec<(String, String)> = Vec::new();
+
+    for (index, entry) in config.mounts.iter().enumerate() {
+        let target_ns = match entry.resolve_ns(&config.mount_namespace, config.pid) {
+            Ok(ns) => ns,
             Err(e) => {
-                eprintln!(
-                    "open mount namespace {} failed: {}",
-                    args.mount_namespace, e
-                );
+                eprintln!("entry {}: {}", index, e);
                 process::exit(1);
             }
         };
-        // CLONE_NEWNS is 0x00020000
-        if let Err(e) = setns(&ns_file, CloneFlags::CLONE_NEWNS) {
-            eprintln!("setns to {} failed: {}", args.mount_namespace, e);
-            process::exit(1);
+        let spec = match entry.to_spec() {
+            Ok(spec) => spec,
+            Err(e) => {
+                eprintln!("entry {}: {}", index, e);
+                process::exit(1);
+            }
+        };
+
+        // The clone of an existing mount tree must happen in whichever
+        // namespace is active before switching to this entry's target,
+        // since that's where a move-mode `spec.source` lives (matching
+        // `run_mount`'s contract for `open_move_source`).
+        let source_fd = match open_move_source(&spec) {
+            Ok(fd) => fd,
+            Err(e) => fail_entry(index, &e, args.rollback, &applied),
+        };
+
+        if current_ns.as_deref() != Some(target_ns.as_str()) {
+            let ns_file = open_ns_file(&target_ns, &target_ns);
+            enter_namespace(&ns_file, &target_ns);
+            current_ns = Some(target_ns.clone());
+        }
+
+        if let Err(e) = apply_mount(&spec, source_fd) {
+            fail_entry(index, &e, args.rollback, &applied);
         }
+        applied.push((entry.target.clone(), target_ns));
     }
 
-    // Create the target directory with permission 755 before move_mount
-    if let Err(e) = std::fs::create_dir_all(target) {
-        eprintln!("failed to create target directory {}: {}", args.target, e);
+    // restore original namespace
+    enter_namespace(&orig_ns, "original mount namespace");
+}
+
+fn run_overlay(args: OverlayArgs) {
+    if args.lowers.is_empty() {
+        eprintln!("overlay requires at least one --lower directory");
         process::exit(1);
     }
-
-    if let Err(e) = std::fs::set_permissions(target, std::fs::Permissions::from_mode(0o755)) {
-        eprintln!(
-            "failed to set permissions on target directory {}: {}",
-            args.target, e
-        );
+    if args.upper.is_some() != args.work.is_some() {
+        eprintln!("--upper and --work must be given together");
         process::exit(1);
     }
+    for lower in &args.lowers {
+        if let Err(e) = validate_host_dir(lower, "lower directory") {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+    for (dir, desc) in [
+        (&args.upper, "upper directory"),
+        (&args.work, "work directory"),
+    ] {
+        let Some(dir) = dir else { continue };
+        if let Err(e) = validate_host_dir(dir, desc) {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
 
-    if let Err(e) = move_mount(
-        source_fd.as_fd(),
-        "",
-        rustix::fs::CWD,
-        target,
-        MoveMountFlags::MOVE_MOUNT_F_EMPTY_PATH,
-    ) {
-        eprintln!("move_mount failed: {}", e);
+    // rightmost --lower is the lowest layer, per the order overlayfs expects
+    let lowerdir = args.lowers.join(":");
+    let (data, flags) = match (&args.upper, &args.work) {
+        (Some(upper), Some(work)) => (
+            format!("lowerdir={},upperdir={},workdir={}", lowerdir, upper, work),
+            Vec::new(),
+        ),
+        // no upper/work: stack read-only image layers
+        _ => (format!("lowerdir={}", lowerdir), vec!["ro".to_string()]),
+    };
+
+    let spec = MountSpec {
+        target: args.target,
+        source: "overlay".to_string(),
+        fstype: Some("overlay".to_string()),
+        options: vec![data],
+        flags,
+        propagation: None,
+        recursive_propagation: false,
+    };
+
+    let orig_ns = open_ns_file("/proc/self/ns/mnt", "original mount namespace");
+    let target_ns = args.ns.resolve();
+    let ns_file = open_ns_file(&target_ns, &target_ns);
+    enter_namespace(&ns_file, &target_ns);
+
+    if let Err(e) = apply_mount(&spec, None) {
+        eprintln!("{}", e);
         process::exit(1);
     }
+
     // restore original namespace
-    if let Err(e) = setns(&orig_ns, CloneFlags::CLONE_NEWNS) {
-        eprintln!("setns back to original namespace failed: {}", e);
-        process::exit(1);
-    }
+    enter_namespace(&orig_ns, "original mount namespace");
 }